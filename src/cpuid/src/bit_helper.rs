@@ -0,0 +1,71 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// An inclusive range of bits, with `msb_index` >= `lsb_index`, both in `[0, 31]`.
+pub struct BitRange {
+    pub msb_index: u32,
+    pub lsb_index: u32,
+}
+
+/// Helpers for reading/writing individual bits or bit ranges of a CPUID register value.
+pub trait BitHelper {
+    /// Reads the bit at `bit_index`.
+    fn read_bit(self, bit_index: u32) -> bool;
+
+    /// Writes `bit_value` at `bit_index`.
+    fn write_bit(&mut self, bit_index: u32, bit_value: bool) -> &mut Self;
+
+    /// Reads the bits in `range`, right-aligned.
+    fn read_bits_in_range(&self, range: &BitRange) -> Self;
+
+    /// Writes `bit_value` into the bits in `range`, leaving the rest of the value untouched.
+    fn write_bits_in_range(&mut self, range: &BitRange, bit_value: Self) -> &mut Self;
+}
+
+impl BitHelper for u32 {
+    fn read_bit(self, bit_index: u32) -> bool {
+        ((self >> bit_index) & 1) == 1
+    }
+
+    fn write_bit(&mut self, bit_index: u32, bit_value: bool) -> &mut Self {
+        *self &= !(1 << bit_index);
+        *self |= u32::from(bit_value) << bit_index;
+        self
+    }
+
+    fn read_bits_in_range(&self, range: &BitRange) -> Self {
+        let shifted_left = self << (31 - range.msb_index);
+        shifted_left >> (31 - range.msb_index + range.lsb_index)
+    }
+
+    fn write_bits_in_range(&mut self, range: &BitRange, bit_value: Self) -> &mut Self {
+        let mask = ((!0_u32) << (31 - range.msb_index) >> (31 - range.msb_index))
+            >> range.lsb_index
+            << range.lsb_index;
+        *self = (*self & !mask) | ((bit_value << range.lsb_index) & mask);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_helper() {
+        let range = BitRange {
+            msb_index: 7,
+            lsb_index: 4,
+        };
+
+        let mut value = 0_u32;
+        value.write_bits_in_range(&range, 0xf);
+        assert_eq!(value, 0xf0);
+        assert_eq!(value.read_bits_in_range(&range), 0xf);
+
+        value.write_bit(0, true);
+        assert!(value.read_bit(0));
+        value.write_bit(0, false);
+        assert!(!value.read_bit(0));
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Constants describing the layout of the CPUID leaves touched by the transformers, grouped
+//! one `mod` per leaf so callers can `use crate::cpu_leaf::leaf_0xNN::*`.
+
+pub mod leaf_0x1 {
+    pub const LEAF_NUM: u32 = 0x1;
+
+    pub mod ecx {
+        pub const HYPERVISOR_BITINDEX: u32 = 31;
+    }
+}
+
+pub mod leaf_0x4 {
+    pub const LEAF_NUM: u32 = 0x4;
+
+    pub mod eax {
+        use crate::bit_helper::BitRange;
+
+        pub const CACHE_LEVEL_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 5,
+        };
+        // Maximum number of addressable IDs for logical processors sharing this cache.
+        pub const MAX_CPUS_PER_CORE_BITRANGE: BitRange = BitRange {
+            msb_index: 25,
+            lsb_index: 14,
+        };
+        // Maximum number of addressable IDs for processor cores in the physical package.
+        pub const MAX_CORES_PER_PACKAGE_BITRANGE: BitRange = BitRange {
+            msb_index: 31,
+            lsb_index: 26,
+        };
+    }
+}
+
+pub mod leaf_0x6 {
+    pub const LEAF_NUM: u32 = 0x6;
+
+    pub mod eax {
+        pub const TURBO_BOOST_BITINDEX: u32 = 1;
+    }
+
+    pub mod ecx {
+        pub const EPB_BITINDEX: u32 = 3;
+    }
+}
+
+pub mod leaf_0xa {
+    pub const LEAF_NUM: u32 = 0xa;
+
+    pub mod eax {
+        use crate::bit_helper::BitRange;
+
+        pub const VERSION_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 0,
+        };
+        pub const NUM_GP_COUNTERS_BITRANGE: BitRange = BitRange {
+            msb_index: 15,
+            lsb_index: 8,
+        };
+        pub const GP_COUNTER_WIDTH_BITRANGE: BitRange = BitRange {
+            msb_index: 23,
+            lsb_index: 16,
+        };
+        pub const EBX_VECTOR_LENGTH_BITRANGE: BitRange = BitRange {
+            msb_index: 31,
+            lsb_index: 24,
+        };
+    }
+
+    pub mod edx {
+        use crate::bit_helper::BitRange;
+
+        pub const NUM_FIXED_COUNTERS_BITRANGE: BitRange = BitRange {
+            msb_index: 4,
+            lsb_index: 0,
+        };
+        pub const FIXED_COUNTER_WIDTH_BITRANGE: BitRange = BitRange {
+            msb_index: 12,
+            lsb_index: 5,
+        };
+    }
+}
+
+pub mod leaf_0xb {
+    pub const LEAF_NUM: u32 = 0xb;
+
+    pub const LEVEL_TYPE_THREAD: u32 = 1;
+    pub const LEVEL_TYPE_CORE: u32 = 2;
+
+    pub mod eax {
+        use crate::bit_helper::BitRange;
+
+        pub const APICID_BITRANGE: BitRange = BitRange {
+            msb_index: 4,
+            lsb_index: 0,
+        };
+    }
+
+    pub mod ebx {
+        use crate::bit_helper::BitRange;
+
+        pub const NUM_LOGICAL_PROCESSORS_BITRANGE: BitRange = BitRange {
+            msb_index: 15,
+            lsb_index: 0,
+        };
+    }
+
+    pub mod ecx {
+        use crate::bit_helper::BitRange;
+
+        pub const LEVEL_NUMBER_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 0,
+        };
+        pub const LEVEL_TYPE_BITRANGE: BitRange = BitRange {
+            msb_index: 15,
+            lsb_index: 8,
+        };
+    }
+}
+
+pub mod leaf_0x7 {
+    pub const LEAF_NUM: u32 = 0x7;
+
+    // Sub-leaf 1 (CPUID.(EAX=7,ECX=1)) feature bits.
+    pub mod subleaf1 {
+        pub mod eax {
+            /// Linear Address Masking support.
+            pub const LAM_BITINDEX: u32 = 26;
+        }
+    }
+}
+
+pub mod leaf_0x1f {
+    pub const LEAF_NUM: u32 = 0x1f;
+
+    pub const LEVEL_TYPE_THREAD: u32 = 1;
+    pub const LEVEL_TYPE_CORE: u32 = 2;
+    pub const LEVEL_TYPE_MODULE: u32 = 3;
+    pub const LEVEL_TYPE_DIE: u32 = 5;
+
+    pub mod eax {
+        use crate::bit_helper::BitRange;
+
+        // Number of bits to shift the x2APIC ID right to get a unique ID for the next level.
+        pub const SHIFT_BITRANGE: BitRange = BitRange {
+            msb_index: 4,
+            lsb_index: 0,
+        };
+    }
+
+    pub mod ebx {
+        use crate::bit_helper::BitRange;
+
+        pub const NUM_LOGICAL_PROCESSORS_BITRANGE: BitRange = BitRange {
+            msb_index: 15,
+            lsb_index: 0,
+        };
+    }
+
+    pub mod ecx {
+        use crate::bit_helper::BitRange;
+
+        pub const LEVEL_NUMBER_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 0,
+        };
+        pub const LEVEL_TYPE_BITRANGE: BitRange = BitRange {
+            msb_index: 15,
+            lsb_index: 8,
+        };
+    }
+}
+
+pub mod leaf_0x80000001 {
+    pub const LEAF_NUM: u32 = 0x8000_0001;
+
+    pub mod ecx {
+        // AMD APM Vol 3: "Topology Extensions" support, gates leaves 0x8000_001D/0x8000_001E.
+        pub const TOPOEXT_BITINDEX: u32 = 22;
+    }
+}
+
+pub mod leaf_0x8000001d {
+    pub const LEAF_NUM: u32 = 0x8000_001d;
+
+    pub mod eax {
+        use crate::bit_helper::BitRange;
+
+        pub const CACHE_LEVEL_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 5,
+        };
+        pub const NUM_SHARING_CACHE_BITRANGE: BitRange = BitRange {
+            msb_index: 25,
+            lsb_index: 14,
+        };
+    }
+}
+
+pub mod leaf_0x8000001e {
+    pub const LEAF_NUM: u32 = 0x8000_001e;
+
+    pub mod ebx {
+        use crate::bit_helper::BitRange;
+
+        pub const COMPUTE_UNIT_ID_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 0,
+        };
+        pub const THREADS_PER_COMPUTE_UNIT_BITRANGE: BitRange = BitRange {
+            msb_index: 15,
+            lsb_index: 8,
+        };
+    }
+
+    pub mod ecx {
+        use crate::bit_helper::BitRange;
+
+        pub const NODE_ID_BITRANGE: BitRange = BitRange {
+            msb_index: 7,
+            lsb_index: 0,
+        };
+        pub const NODES_PER_PROCESSOR_BITRANGE: BitRange = BitRange {
+            msb_index: 10,
+            lsb_index: 8,
+        };
+    }
+}
@@ -0,0 +1,9 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities for normalizing the CPUID leaves exposed to a guest, so that vCPUs behave
+//! consistently no matter which physical host they end up running on.
+
+pub mod bit_helper;
+pub mod cpu_leaf;
+pub mod transformer;
@@ -0,0 +1,158 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::bit_helper::BitHelper;
+use crate::cpu_leaf::*;
+
+fn update_extended_feature_info_entry(
+    entry: &mut kvm_cpuid_entry2,
+    _vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0x80000001::*;
+
+    // Advertise "Topology Extensions" so the guest actually walks leaves 0x8000_001D and
+    // 0x8000_001E instead of falling back to the legacy flat topology.
+    entry.ecx.write_bit(ecx::TOPOEXT_BITINDEX, true);
+
+    Ok(())
+}
+
+fn update_extended_cache_topology_entry(
+    entry: &mut kvm_cpuid_entry2,
+    vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0x8000001d::*;
+
+    // Sub-leaves past the last cache level come back all zeroes; nothing to synthesize there.
+    if entry.eax.read_bits_in_range(&eax::CACHE_LEVEL_BITRANGE) == 0 {
+        return Ok(());
+    }
+
+    // leaf_0x8000001d's EAX layout (CACHE_LEVEL_BITRANGE/NUM_SHARING_CACHE_BITRANGE) is
+    // bit-for-bit identical to leaf 0x4's, so reuse the shared per-level cache-sharing logic
+    // instead of reimplementing it (and getting L3 wrong) here.
+    common::update_cache_parameters_entry(entry, vm_spec)
+}
+
+fn update_extended_apic_id_entry(
+    entry: &mut kvm_cpuid_entry2,
+    vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0x8000001e::*;
+
+    // EAX: extended APIC ID of the current logical processor.
+    entry.eax = u32::from(vm_spec.cpu_index);
+
+    // EBX: compute-unit ID (one per core) and the number of threads sharing it.
+    entry.ebx.write_bits_in_range(
+        &ebx::COMPUTE_UNIT_ID_BITRANGE,
+        u32::from(vm_spec.cpu_index) >> u32::from(vm_spec.cpu_bits),
+    );
+    entry.ebx.write_bits_in_range(
+        &ebx::THREADS_PER_COMPUTE_UNIT_BITRANGE,
+        u32::from(vm_spec.cpus_per_core()) - 1,
+    );
+
+    // ECX: single-node, single-socket guest topology.
+    entry.ecx.write_bits_in_range(&ecx::NODE_ID_BITRANGE, 0);
+    entry
+        .ecx
+        .write_bits_in_range(&ecx::NODES_PER_PROCESSOR_BITRANGE, 0);
+
+    Ok(())
+}
+
+pub struct AmdCpuidTransformer {}
+
+impl CpuidTransformer for AmdCpuidTransformer {
+    fn entry_transformer_fn(&self, entry: &mut kvm_cpuid_entry2) -> Option<EntryTransformerFn> {
+        match entry.function {
+            leaf_0x1::LEAF_NUM => Some(common::update_feature_info_entry),
+            leaf_0x80000001::LEAF_NUM => Some(amd::update_extended_feature_info_entry),
+            leaf_0x8000001d::LEAF_NUM => Some(amd::update_extended_cache_topology_entry),
+            leaf_0x8000001e::LEAF_NUM => Some(amd::update_extended_apic_id_entry),
+            0x8000_0002..=0x8000_0004 => Some(common::update_brand_string_entry),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kvm_bindings::kvm_cpuid_entry2;
+
+    use super::*;
+    use crate::transformer::VmSpec;
+
+    fn build_entry(function: u32) -> kvm_cpuid_entry2 {
+        kvm_cpuid_entry2 {
+            function,
+            index: 0,
+            flags: 0,
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        }
+    }
+
+    #[test]
+    fn test_update_extended_feature_info_entry() {
+        let vm_spec = VmSpec::new(0, 1, false).expect("Error creating vm_spec");
+        let mut entry = &mut build_entry(leaf_0x80000001::LEAF_NUM);
+
+        assert!(update_extended_feature_info_entry(&mut entry, &vm_spec).is_ok());
+        assert!(entry.ecx.read_bit(leaf_0x80000001::ecx::TOPOEXT_BITINDEX));
+    }
+
+    #[test]
+    fn test_update_extended_apic_id_entry() {
+        use crate::cpu_leaf::leaf_0x8000001e::*;
+
+        let vm_spec = VmSpec::new(3, 4, true).expect("Error creating vm_spec");
+        let mut entry = &mut build_entry(leaf_0x8000001e::LEAF_NUM);
+
+        assert!(update_extended_apic_id_entry(&mut entry, &vm_spec).is_ok());
+
+        assert_eq!(entry.eax, 3);
+        assert_eq!(entry.ebx.read_bits_in_range(&ebx::COMPUTE_UNIT_ID_BITRANGE), 1);
+        assert_eq!(
+            entry
+                .ebx
+                .read_bits_in_range(&ebx::THREADS_PER_COMPUTE_UNIT_BITRANGE),
+            1
+        );
+        assert_eq!(entry.ecx.read_bits_in_range(&ecx::NODE_ID_BITRANGE), 0);
+    }
+
+    #[test]
+    fn test_update_extended_cache_topology_entry() {
+        use crate::cpu_leaf::leaf_0x8000001d::*;
+
+        let vm_spec = VmSpec::new(0, 2, true).expect("Error creating vm_spec");
+        let mut entry = &mut build_entry(leaf_0x8000001d::LEAF_NUM);
+        entry
+            .eax
+            .write_bits_in_range(&eax::CACHE_LEVEL_BITRANGE, 1);
+
+        assert!(update_extended_cache_topology_entry(&mut entry, &vm_spec).is_ok());
+        assert_eq!(entry.eax.read_bits_in_range(&eax::NUM_SHARING_CACHE_BITRANGE), 1);
+    }
+
+    #[test]
+    fn test_update_extended_cache_topology_entry_l3() {
+        use crate::cpu_leaf::leaf_0x8000001d::*;
+
+        // 4 cores, no SMT: L3 is shared by the whole package, not just SMT siblings.
+        let vm_spec = VmSpec::new(0, 4, false).expect("Error creating vm_spec");
+        let mut entry = &mut build_entry(leaf_0x8000001d::LEAF_NUM);
+        entry
+            .eax
+            .write_bits_in_range(&eax::CACHE_LEVEL_BITRANGE, 3);
+
+        assert!(update_extended_cache_topology_entry(&mut entry, &vm_spec).is_ok());
+        assert_eq!(entry.eax.read_bits_in_range(&eax::NUM_SHARING_CACHE_BITRANGE), 3);
+    }
+}
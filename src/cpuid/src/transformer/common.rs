@@ -0,0 +1,58 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::bit_helper::BitHelper;
+
+/// Updates the feature info entry (leaf 0x1), shared by Intel and AMD.
+pub fn update_feature_info_entry(
+    entry: &mut kvm_cpuid_entry2,
+    _vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0x1::*;
+
+    // Hide the "running under a hypervisor" bit so guests that key behavior off of it see a
+    // consistent answer regardless of the underlying KVM/host configuration.
+    entry.ecx.write_bit(ecx::HYPERVISOR_BITINDEX, false);
+
+    Ok(())
+}
+
+/// Updates the logical-processors-sharing-this-cache field of a deterministic cache leaf,
+/// shared by Intel (leaf 0x4) and AMD (leaf 0x8000_001D), which use the same EAX layout.
+pub fn update_cache_parameters_entry(
+    entry: &mut kvm_cpuid_entry2,
+    vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0x4::eax::*;
+
+    match entry.eax.read_bits_in_range(&CACHE_LEVEL_BITRANGE) {
+        // L1 & L2 cache are only shared by the logical threads of a core.
+        1 | 2 => {
+            entry.eax.write_bits_in_range(
+                &MAX_CPUS_PER_CORE_BITRANGE,
+                u32::from(vm_spec.cpus_per_core()) - 1,
+            );
+        }
+        // L3 cache is shared by the whole package, i.e. cores_per_socket (not the whole
+        // machine), matching MAX_CORES_PER_PACKAGE.
+        3 => {
+            entry.eax.write_bits_in_range(
+                &MAX_CPUS_PER_CORE_BITRANGE,
+                u32::from(vm_spec.cores_per_socket) * u32::from(vm_spec.cpus_per_core()) - 1,
+            );
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// The brand string entries (0x8000_0002-0x8000_0004) are passed straight through from KVM on
+/// both vendors, so there is nothing to rewrite here.
+pub fn update_brand_string_entry(
+    _entry: &mut kvm_cpuid_entry2,
+    _vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    Ok(())
+}
@@ -17,10 +17,11 @@ fn update_deterministic_cache_entry(
 
     common::update_cache_parameters_entry(entry, vm_spec)?;
 
-    // Put all the cores in the same socket
+    // Cores sharing this package, i.e. cores_per_socket (not the whole machine, now that a
+    // VmSpec can describe more than one socket).
     entry.eax.write_bits_in_range(
         &eax::MAX_CORES_PER_PACKAGE_BITRANGE,
-        u32::from(vm_spec.cpu_count / vm_spec.cpus_per_core()) - 1,
+        u32::from(vm_spec.cores_per_socket) - 1,
     );
 
     Ok(())
@@ -39,13 +40,62 @@ fn update_power_management_entry(
     Ok(())
 }
 
-fn update_perf_mon_entry(entry: &mut kvm_cpuid_entry2, _vm_spec: &VmSpec) -> Result<(), Error> {
-    // Architectural Performance Monitor Leaf
-    // Disable PMU
+fn update_perf_mon_entry(entry: &mut kvm_cpuid_entry2, vm_spec: &VmSpec) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0xa::*;
+
+    // Architectural Performance Monitor Leaf. Disabled by default, so that guest behavior stays
+    // deterministic across hosts with different performance counters.
+    if vm_spec.pmu != PmuMode::Enabled {
+        entry.eax = 0;
+        entry.ebx = 0;
+        entry.ecx = 0;
+        entry.edx = 0;
+
+        return Ok(());
+    }
+
+    // `entry` was pre-populated by KVM_GET_SUPPORTED_CPUID, so everything we read here is
+    // already what the host can actually back; we only need to require version 2+ and drop
+    // any reserved bits.
+    let host_version = entry.eax.read_bits_in_range(&eax::VERSION_BITRANGE);
+    if host_version < 2 {
+        entry.eax = 0;
+        entry.ebx = 0;
+        entry.ecx = 0;
+        entry.edx = 0;
+
+        return Ok(());
+    }
+
+    let host_num_gp_counters = entry.eax.read_bits_in_range(&eax::NUM_GP_COUNTERS_BITRANGE);
+    let host_gp_counter_width = entry.eax.read_bits_in_range(&eax::GP_COUNTER_WIDTH_BITRANGE);
+    let host_ebx_vector_length = entry.eax.read_bits_in_range(&eax::EBX_VECTOR_LENGTH_BITRANGE);
+    let host_ebx_mask = entry.ebx;
+    let host_num_fixed_counters = entry.edx.read_bits_in_range(&edx::NUM_FIXED_COUNTERS_BITRANGE);
+    let host_fixed_counter_width = entry.edx.read_bits_in_range(&edx::FIXED_COUNTER_WIDTH_BITRANGE);
+
     entry.eax = 0;
-    entry.ebx = 0;
+    entry.eax.write_bits_in_range(&eax::VERSION_BITRANGE, host_version);
+    entry
+        .eax
+        .write_bits_in_range(&eax::NUM_GP_COUNTERS_BITRANGE, host_num_gp_counters);
+    entry
+        .eax
+        .write_bits_in_range(&eax::GP_COUNTER_WIDTH_BITRANGE, host_gp_counter_width);
+    entry
+        .eax
+        .write_bits_in_range(&eax::EBX_VECTOR_LENGTH_BITRANGE, host_ebx_vector_length);
+
+    entry.ebx = host_ebx_mask;
     entry.ecx = 0;
+
     entry.edx = 0;
+    entry
+        .edx
+        .write_bits_in_range(&edx::NUM_FIXED_COUNTERS_BITRANGE, host_num_fixed_counters);
+    entry
+        .edx
+        .write_bits_in_range(&edx::FIXED_COUNTER_WIDTH_BITRANGE, host_fixed_counter_width);
 
     Ok(())
 }
@@ -89,12 +139,22 @@ fn update_extended_topology_entry(
         }
         // Core Level Processor Topology; index = 1
         1 => {
+            // The shift has to clear enough high bits to give every package a distinct APIC-ID
+            // range, so fall back to the historical fixed width only when it's still wide
+            // enough for this socket's core count.
+            let core_level_shift = std::cmp::max(
+                LEAFBH_INDEX1_APICID,
+                apicid_shift_width(u32::from(vm_spec.cores_per_socket) * u32::from(vm_spec.cpus_per_core())),
+            );
             entry
                 .eax
-                .write_bits_in_range(&eax::APICID_BITRANGE, LEAFBH_INDEX1_APICID);
+                .write_bits_in_range(&eax::APICID_BITRANGE, core_level_shift);
+            // Logical processors in this vCPU's package only, consistent with
+            // MAX_CORES_PER_PACKAGE in leaf 0x4 -
+            // not the whole machine, now that a VmSpec can describe more than one socket.
             entry.ebx.write_bits_in_range(
                 &ebx::NUM_LOGICAL_PROCESSORS_BITRANGE,
-                u32::from(vm_spec.cpu_count),
+                u32::from(vm_spec.cores_per_socket) * u32::from(vm_spec.cpus_per_core()),
             );
             entry
                 .ecx
@@ -115,6 +175,107 @@ fn update_extended_topology_entry(
     Ok(())
 }
 
+// Returns the number of bits needed to uniquely represent `num_logical_processors` IDs
+// starting at 0, i.e. ceil(log2(num_logical_processors)).
+fn apicid_shift_width(num_logical_processors: u32) -> u32 {
+    if num_logical_processors <= 1 {
+        0
+    } else {
+        32 - (num_logical_processors - 1).leading_zeros()
+    }
+}
+
+fn update_v2_extended_topology_entry(
+    entry: &mut kvm_cpuid_entry2,
+    vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    use crate::cpu_leaf::leaf_0x1f::*;
+
+    // reset eax, ebx, ecx
+    entry.eax = 0_u32;
+    entry.ebx = 0_u32;
+    entry.ecx = 0_u32;
+    // EDX bits 31..0 contain the full x2APIC ID of the current logical processor.
+    entry.edx = u32::from(vm_spec.cpu_index);
+
+    entry
+        .ecx
+        .write_bits_in_range(&ecx::LEVEL_NUMBER_BITRANGE, entry.index);
+
+    let threads_per_core = u32::from(vm_spec.cpus_per_core());
+    let cores_per_module = u32::from(vm_spec.cores_per_module);
+    let modules_per_die = u32::from(vm_spec.modules_per_die);
+    let dies_per_package = u32::from(vm_spec.dies_per_package);
+
+    // Each level's processor count is the product of everything below it; the shift width is
+    // derived from that count, so each level's shift and processor count agree. Unlike leaf
+    // 0xB's Core level, no 7-bit minimum is applied to the shift.
+    let levels = [
+        (LEVEL_TYPE_THREAD, threads_per_core),
+        (LEVEL_TYPE_CORE, threads_per_core * cores_per_module),
+        (
+            LEVEL_TYPE_MODULE,
+            threads_per_core * cores_per_module * modules_per_die,
+        ),
+        (
+            LEVEL_TYPE_DIE,
+            threads_per_core * cores_per_module * modules_per_die * dies_per_package,
+        ),
+    ];
+
+    // Levels past the last valid one are left at level type 0 (invalid), with EAX/EBX cleared.
+    if let Some(&(level_type, num_logical_processors)) = levels.get(entry.index as usize) {
+        entry
+            .eax
+            .write_bits_in_range(&eax::SHIFT_BITRANGE, apicid_shift_width(num_logical_processors));
+        entry
+            .ebx
+            .write_bits_in_range(&ebx::NUM_LOGICAL_PROCESSORS_BITRANGE, num_logical_processors);
+        entry
+            .ecx
+            .write_bits_in_range(&ecx::LEVEL_TYPE_BITRANGE, level_type);
+    }
+
+    Ok(())
+}
+
+fn update_structured_extended_entry(
+    entry: &mut kvm_cpuid_entry2,
+    vm_spec: &VmSpec,
+) -> Result<(), Error> {
+    for mask in &vm_spec.feature_masks {
+        if mask.leaf != entry.function || mask.subleaf != entry.index {
+            continue;
+        }
+
+        let register = match mask.register {
+            CpuidRegister::Eax => &mut entry.eax,
+            CpuidRegister::Ebx => &mut entry.ebx,
+            CpuidRegister::Ecx => &mut entry.ecx,
+            CpuidRegister::Edx => &mut entry.edx,
+        };
+
+        match mask.action {
+            // Never claim a feature the host didn't already report: assert the bit is there
+            // rather than silently leaving it clear.
+            MaskAction::ForceSet => {
+                if !register.read_bit(mask.bit) {
+                    return Err(Error::UnsupportedFeatureMask(format!(
+                        "CPUID leaf {:#x} sub-leaf {} {:?} bit {} is required by the CPU \
+                         template, but is not supported by this host.",
+                        mask.leaf, mask.subleaf, mask.register, mask.bit
+                    )));
+                }
+            }
+            MaskAction::ForceClear => {
+                register.write_bit(mask.bit, false);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub struct IntelCpuidTransformer {}
 
 impl CpuidTransformer for IntelCpuidTransformer {
@@ -123,8 +284,10 @@ impl CpuidTransformer for IntelCpuidTransformer {
             leaf_0x1::LEAF_NUM => Some(common::update_feature_info_entry),
             leaf_0x4::LEAF_NUM => Some(intel::update_deterministic_cache_entry),
             leaf_0x6::LEAF_NUM => Some(intel::update_power_management_entry),
+            leaf_0x7::LEAF_NUM => Some(intel::update_structured_extended_entry),
             leaf_0xa::LEAF_NUM => Some(intel::update_perf_mon_entry),
             leaf_0xb::LEAF_NUM => Some(intel::update_extended_topology_entry),
+            leaf_0x1f::LEAF_NUM => Some(intel::update_v2_extended_topology_entry),
             0x8000_0002..=0x8000_0004 => Some(common::update_brand_string_entry),
             _ => None,
         }
@@ -161,6 +324,79 @@ mod tests {
         assert_eq!(entry.edx, 0);
     }
 
+    #[test]
+    fn test_update_perf_mon_entry_pmu_enabled() {
+        use crate::cpu_leaf::leaf_0xa::*;
+
+        let vm_spec = VmSpec::new(0, 1, false)
+            .expect("Error creating vm_spec")
+            .with_pmu(PmuMode::Enabled);
+
+        // Simulate what KVM_GET_SUPPORTED_CPUID would have filled in: version 2, 4 GP
+        // counters, 48-bit width, an 8-bit EBX vector, 3 fixed counters at 48 bits, with a
+        // reserved bit set in ECX that must not survive.
+        let mut host_eax = 0_u32;
+        host_eax.write_bits_in_range(&eax::VERSION_BITRANGE, 2);
+        host_eax.write_bits_in_range(&eax::NUM_GP_COUNTERS_BITRANGE, 4);
+        host_eax.write_bits_in_range(&eax::GP_COUNTER_WIDTH_BITRANGE, 48);
+        host_eax.write_bits_in_range(&eax::EBX_VECTOR_LENGTH_BITRANGE, 8);
+
+        let mut host_edx = 0_u32;
+        host_edx.write_bits_in_range(&edx::NUM_FIXED_COUNTERS_BITRANGE, 3);
+        host_edx.write_bits_in_range(&edx::FIXED_COUNTER_WIDTH_BITRANGE, 48);
+
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: leaf_0xa::LEAF_NUM,
+            index: 0,
+            flags: 0,
+            eax: host_eax,
+            ebx: 0b1111_1111,
+            ecx: 0xffff_ffff,
+            edx: host_edx,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_perf_mon_entry(&mut entry, &vm_spec).is_ok());
+
+        assert_eq!(entry.eax.read_bits_in_range(&eax::VERSION_BITRANGE), 2);
+        assert_eq!(entry.eax.read_bits_in_range(&eax::NUM_GP_COUNTERS_BITRANGE), 4);
+        assert_eq!(entry.eax.read_bits_in_range(&eax::GP_COUNTER_WIDTH_BITRANGE), 48);
+        assert_eq!(entry.eax.read_bits_in_range(&eax::EBX_VECTOR_LENGTH_BITRANGE), 8);
+        assert_eq!(entry.ebx, 0b1111_1111);
+        assert_eq!(entry.ecx, 0);
+        assert_eq!(entry.edx.read_bits_in_range(&edx::NUM_FIXED_COUNTERS_BITRANGE), 3);
+        assert_eq!(
+            entry.edx.read_bits_in_range(&edx::FIXED_COUNTER_WIDTH_BITRANGE),
+            48
+        );
+    }
+
+    #[test]
+    fn test_update_perf_mon_entry_pmu_enabled_unsupported_host() {
+        let vm_spec = VmSpec::new(0, 1, false)
+            .expect("Error creating vm_spec")
+            .with_pmu(PmuMode::Enabled);
+
+        // Host reports architectural PMU version 0 (unsupported): still fully disabled.
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: leaf_0xa::LEAF_NUM,
+            index: 0,
+            flags: 0,
+            eax: 0,
+            ebx: 1,
+            ecx: 1,
+            edx: 1,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_perf_mon_entry(&mut entry, &vm_spec).is_ok());
+
+        assert_eq!(entry.eax, 0);
+        assert_eq!(entry.ebx, 0);
+        assert_eq!(entry.ecx, 0);
+        assert_eq!(entry.edx, 0);
+    }
+
     fn check_update_deterministic_cache_entry(
         cpu_count: u8,
         smt: bool,
@@ -293,4 +529,281 @@ mod tests {
         // index 1
         check_update_extended_topology_entry(2, true, 1, LEAFBH_INDEX1_APICID, 2, LEVEL_TYPE_CORE);
     }
+
+    fn check_update_v2_extended_topology_entry(
+        vm_spec: &VmSpec,
+        index: u32,
+        expected_shift: u32,
+        expected_num_logical_processors: u32,
+        expected_level_type: u32,
+    ) {
+        use crate::cpu_leaf::leaf_0x1f::*;
+
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: leaf_0x1f::LEAF_NUM,
+            index,
+            flags: 0,
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_v2_extended_topology_entry(&mut entry, vm_spec).is_ok());
+
+        assert_eq!(entry.edx, u32::from(vm_spec.cpu_index));
+        assert_eq!(entry.eax.read_bits_in_range(&eax::SHIFT_BITRANGE), expected_shift);
+        assert_eq!(
+            entry
+                .ebx
+                .read_bits_in_range(&ebx::NUM_LOGICAL_PROCESSORS_BITRANGE),
+            expected_num_logical_processors
+        );
+        assert_eq!(entry.ecx.read_bits_in_range(&ecx::LEVEL_TYPE_BITRANGE), expected_level_type);
+        assert_eq!(entry.ecx.read_bits_in_range(&ecx::LEVEL_NUMBER_BITRANGE), index);
+    }
+
+    #[test]
+    fn test_update_v2_extended_topology_entry() {
+        use crate::cpu_leaf::leaf_0x1f::{LEVEL_TYPE_CORE, LEVEL_TYPE_DIE, LEVEL_TYPE_MODULE, LEVEL_TYPE_THREAD};
+
+        // 16 vCPUs: 2 threads/core, 2 cores/module, 2 modules/die, 2 dies/package.
+        let vm_spec = VmSpec::new(5, 16, true)
+            .expect("Error creating vm_spec")
+            .with_cores_per_module(2)
+            .expect("Error setting cores_per_module")
+            .with_modules_per_die(2)
+            .expect("Error setting modules_per_die")
+            .with_dies_per_package(2)
+            .expect("Error setting dies_per_package");
+
+        check_update_v2_extended_topology_entry(&vm_spec, 0, 1, 2, LEVEL_TYPE_THREAD);
+        check_update_v2_extended_topology_entry(&vm_spec, 1, 2, 4, LEVEL_TYPE_CORE);
+        check_update_v2_extended_topology_entry(&vm_spec, 2, 3, 8, LEVEL_TYPE_MODULE);
+        check_update_v2_extended_topology_entry(&vm_spec, 3, 4, 16, LEVEL_TYPE_DIE);
+
+        // Past the last valid level: invalid, with EAX/EBX cleared.
+        check_update_v2_extended_topology_entry(&vm_spec, 4, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_update_deterministic_cache_entry_multi_socket() {
+        use crate::cpu_leaf::leaf_0x4::*;
+
+        // 2 sockets * 4 cores/socket, no SMT.
+        let vm_spec = VmSpec::new(0, 8, false)
+            .expect("Error creating vm_spec")
+            .with_socket_topology(2, 4)
+            .expect("Error setting socket topology");
+
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: 0x0,
+            index: 0,
+            flags: 0,
+            eax: *(0_u32).write_bits_in_range(&eax::CACHE_LEVEL_BITRANGE, 3),
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_deterministic_cache_entry(&mut entry, &vm_spec).is_ok());
+
+        // MAX_CORES_PER_PACKAGE reflects the 4 cores in this vCPU's socket, not all 8 vCPUs.
+        assert_eq!(
+            entry.eax.read_bits_in_range(&eax::MAX_CORES_PER_PACKAGE_BITRANGE),
+            3
+        );
+        // The L3 cache's "processors sharing this cache" field must agree: 4, not 8.
+        assert_eq!(
+            entry.eax.read_bits_in_range(&eax::MAX_CPUS_PER_CORE_BITRANGE),
+            3
+        );
+    }
+
+    #[test]
+    fn test_update_extended_topology_entry_multi_socket() {
+        use crate::cpu_leaf::leaf_0xb::*;
+
+        // 2 sockets * 4 cores/socket, no SMT.
+        let vm_spec = VmSpec::new(0, 8, false)
+            .expect("Error creating vm_spec")
+            .with_socket_topology(2, 4)
+            .expect("Error setting socket topology");
+
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: 0x0,
+            index: 1,
+            flags: 0,
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_extended_topology_entry(&mut entry, &vm_spec).is_ok());
+
+        // The Core level must agree with leaf 0x4's MAX_CORES_PER_PACKAGE: 4 cores in this
+        // package, not all 8 vCPUs in the machine.
+        assert_eq!(
+            entry
+                .ebx
+                .read_bits_in_range(&ebx::NUM_LOGICAL_PROCESSORS_BITRANGE),
+            4
+        );
+    }
+
+    #[test]
+    fn test_update_extended_topology_entry_wide_socket() {
+        use crate::cpu_leaf::leaf_0xb::*;
+
+        // A single socket with 150 cores needs 8 APIC-ID bits, one more than the historical
+        // fixed 7-bit (128-core) budget.
+        let vm_spec = VmSpec::new(0, 150, false)
+            .expect("Error creating vm_spec")
+            .with_socket_topology(1, 150)
+            .expect("Error setting socket topology");
+
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: 0x0,
+            index: 1,
+            flags: 0,
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_extended_topology_entry(&mut entry, &vm_spec).is_ok());
+        assert_eq!(entry.eax.read_bits_in_range(&eax::APICID_BITRANGE), 8);
+    }
+
+    #[test]
+    fn test_update_structured_extended_entry_force_clear() {
+        use crate::cpu_leaf::leaf_0x7::*;
+
+        // Hide LAM (subleaf-1 EAX bit), even though the host reports it as supported.
+        let vm_spec = VmSpec::new(0, 1, false)
+            .expect("Error creating vm_spec")
+            .with_feature_masks(vec![CpuidFeatureMask {
+                leaf: LEAF_NUM,
+                subleaf: 1,
+                register: CpuidRegister::Eax,
+                bit: subleaf1::eax::LAM_BITINDEX,
+                action: MaskAction::ForceClear,
+            }]);
+
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: LEAF_NUM,
+            index: 1,
+            flags: 0,
+            eax: 1 << subleaf1::eax::LAM_BITINDEX,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_structured_extended_entry(&mut entry, &vm_spec).is_ok());
+        assert!(!entry.eax.read_bit(subleaf1::eax::LAM_BITINDEX));
+    }
+
+    #[test]
+    fn test_update_structured_extended_entry_force_set_requires_host_support() {
+        use crate::cpu_leaf::leaf_0x7::*;
+
+        let vm_spec = VmSpec::new(0, 1, false)
+            .expect("Error creating vm_spec")
+            .with_feature_masks(vec![CpuidFeatureMask {
+                leaf: LEAF_NUM,
+                subleaf: 1,
+                register: CpuidRegister::Eax,
+                bit: subleaf1::eax::LAM_BITINDEX,
+                action: MaskAction::ForceSet,
+            }]);
+
+        // Host doesn't actually support LAM: force-set must fail rather than fabricate the bit.
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: LEAF_NUM,
+            index: 1,
+            flags: 0,
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert_eq!(
+            update_structured_extended_entry(&mut entry, &vm_spec),
+            Err(Error::UnsupportedFeatureMask(
+                "CPUID leaf 0x7 sub-leaf 1 Eax bit 26 is required by the CPU template, but is \
+                 not supported by this host."
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_update_structured_extended_entry_force_set_with_host_support() {
+        use crate::cpu_leaf::leaf_0x7::*;
+
+        let vm_spec = VmSpec::new(0, 1, false)
+            .expect("Error creating vm_spec")
+            .with_feature_masks(vec![CpuidFeatureMask {
+                leaf: LEAF_NUM,
+                subleaf: 1,
+                register: CpuidRegister::Eax,
+                bit: subleaf1::eax::LAM_BITINDEX,
+                action: MaskAction::ForceSet,
+            }]);
+
+        // Host already reports LAM as supported: force-set succeeds and leaves the bit set.
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: LEAF_NUM,
+            index: 1,
+            flags: 0,
+            eax: 1 << subleaf1::eax::LAM_BITINDEX,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_structured_extended_entry(&mut entry, &vm_spec).is_ok());
+        assert!(entry.eax.read_bit(subleaf1::eax::LAM_BITINDEX));
+    }
+
+    #[test]
+    fn test_update_structured_extended_entry_ignores_other_subleaves() {
+        use crate::cpu_leaf::leaf_0x7::*;
+
+        let vm_spec = VmSpec::new(0, 1, false)
+            .expect("Error creating vm_spec")
+            .with_feature_masks(vec![CpuidFeatureMask {
+                leaf: LEAF_NUM,
+                subleaf: 1,
+                register: CpuidRegister::Eax,
+                bit: subleaf1::eax::LAM_BITINDEX,
+                action: MaskAction::ForceClear,
+            }]);
+
+        // Sub-leaf 0 isn't targeted by the mask, so it must be left untouched.
+        let mut entry = &mut kvm_cpuid_entry2 {
+            function: LEAF_NUM,
+            index: 0,
+            flags: 0,
+            eax: 1 << subleaf1::eax::LAM_BITINDEX,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+            padding: [0, 0, 0],
+        };
+
+        assert!(update_structured_extended_entry(&mut entry, &vm_spec).is_ok());
+        assert!(entry.eax.read_bit(subleaf1::eax::LAM_BITINDEX));
+    }
 }
@@ -0,0 +1,280 @@
+// Copyright 2019 Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod amd;
+pub mod common;
+pub mod intel;
+
+pub use kvm_bindings::kvm_cpuid_entry2;
+
+/// Errors associated with building or applying a CPUID transformation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The `VmSpec` the transformer was asked to apply is not internally consistent.
+    InvalidVmSpec(String),
+    /// A `CpuidFeatureMask` with `MaskAction::ForceSet` targeted a bit the host does not
+    /// actually support, so the CPU template cannot be honored on this host.
+    UnsupportedFeatureMask(String),
+}
+
+/// A function that updates a single CPUID entry in place, given the target `VmSpec`.
+pub type EntryTransformerFn = fn(&mut kvm_cpuid_entry2, &VmSpec) -> Result<(), Error>;
+
+/// A vendor-specific transformation applied to the CPUID leaves passed through to a guest.
+pub trait CpuidTransformer {
+    /// Returns the function that should be used to transform `entry`, if any.
+    fn entry_transformer_fn(&self, entry: &mut kvm_cpuid_entry2) -> Option<EntryTransformerFn>;
+
+    /// Applies `entry_transformer_fn` to every entry of `cpuid`.
+    fn process_cpuid(
+        &self,
+        cpuid: &mut kvm_bindings::CpuId,
+        vm_spec: &VmSpec,
+    ) -> Result<(), Error> {
+        for entry in cpuid.as_mut_slice().iter_mut() {
+            if let Some(transformer_fn) = self.entry_transformer_fn(entry) {
+                transformer_fn(entry, vm_spec)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Controls whether the architectural PMU (leaf 0xA) is exposed to the guest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PmuMode {
+    /// Leaf 0xA is fully zeroed out, hiding the vPMU from the guest. This is the default: it
+    /// keeps guest behavior deterministic across hosts with different performance counters.
+    Disabled,
+    /// Leaf 0xA is populated for Architectural Performance Monitoring, clamped to whatever
+    /// KVM reports as supported on this host.
+    Enabled,
+}
+
+/// The CPUID output register a `CpuidFeatureMask` applies to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CpuidRegister {
+    Eax,
+    Ebx,
+    Ecx,
+    Edx,
+}
+
+/// What to do with the targeted bit once it has been located.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaskAction {
+    /// Assert the bit is on. Errors with `Error::UnsupportedFeatureMask` if the host/KVM
+    /// didn't already report it as supported, rather than silently claiming a feature the
+    /// hardware doesn't have.
+    ForceSet,
+    /// Turn the bit off, hiding the feature from the guest even if the host supports it.
+    ForceClear,
+}
+
+/// A single declarative rule in a CPU template: force a specific (leaf, sub-leaf, register,
+/// bit) on or off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpuidFeatureMask {
+    /// The CPUID leaf (`entry.function`) this rule applies to.
+    pub leaf: u32,
+    /// The CPUID sub-leaf (`entry.index`) this rule applies to.
+    pub subleaf: u32,
+    /// Which output register holds the targeted bit.
+    pub register: CpuidRegister,
+    /// The bit index within `register`.
+    pub bit: u32,
+    /// Whether to force the bit on or off.
+    pub action: MaskAction,
+}
+
+/// Describes the vCPU topology the CPUID leaves should present to the guest.
+#[derive(Clone, Debug)]
+pub struct VmSpec {
+    /// Index of the current logical CPU, in `[0, cpu_count)`.
+    pub cpu_index: u8,
+    /// Total number of logical CPUs exposed to the guest.
+    pub cpu_count: u8,
+    /// Whether simultaneous multithreading is enabled.
+    pub smt: bool,
+    /// Number of bits needed to enumerate the logical CPUs sharing a core.
+    pub cpu_bits: u8,
+    /// Number of sockets/packages exposed to the guest. Defaults to 1.
+    pub sockets: u8,
+    /// Number of cores in each socket/package. Defaults to every core in the machine, i.e. a
+    /// single flat package.
+    pub cores_per_socket: u8,
+    /// Number of cores grouped into a single module, for leaf 0x1F. Defaults to every core in
+    /// the (single, flat) package.
+    pub cores_per_module: u8,
+    /// Number of modules grouped into a single die, for leaf 0x1F. Defaults to 1.
+    pub modules_per_die: u8,
+    /// Number of dies grouped into a single package, for leaf 0x1F. Defaults to 1.
+    pub dies_per_package: u8,
+    /// Whether the architectural PMU (leaf 0xA) is exposed to the guest. Defaults to disabled.
+    pub pmu: PmuMode,
+    /// CPU template rules applied by `intel::update_structured_extended_entry` (leaf 0x7).
+    /// Defaults to empty, i.e. whatever the host/KVM reports is passed straight through.
+    pub feature_masks: Vec<CpuidFeatureMask>,
+}
+
+impl VmSpec {
+    /// Creates a new `VmSpec`, deriving the APIC ID bit width from `cpu_count` and `smt`.
+    ///
+    /// The leaf 0x1F module/die/package topology defaults to a single flat package, matching
+    /// the layout the rest of the transformers assume.
+    pub fn new(cpu_index: u8, cpu_count: u8, smt: bool) -> Result<Self, Error> {
+        if cpu_count == 0 {
+            return Err(Error::InvalidVmSpec(
+                "The number of vCPUs cannot be 0.".to_string(),
+            ));
+        }
+
+        let cpu_bits = u8::from(smt && cpu_count > 1);
+        let cores_per_socket = cpu_count / (1 << cpu_bits);
+
+        Ok(VmSpec {
+            cpu_index,
+            cpu_count,
+            smt,
+            cpu_bits,
+            sockets: 1,
+            cores_per_socket,
+            cores_per_module: cores_per_socket,
+            modules_per_die: 1,
+            dies_per_package: 1,
+            pmu: PmuMode::Disabled,
+            feature_masks: Vec::new(),
+        })
+    }
+
+    /// Returns the number of logical CPUs per core (1, or 2 when SMT is enabled).
+    pub fn cpus_per_core(&self) -> u8 {
+        1 << self.cpu_bits
+    }
+
+    /// Overrides the default single-socket layout with an explicit `sockets` /
+    /// `cores_per_socket` breakdown. `sockets * cores_per_socket * threads_per_core` must equal
+    /// `cpu_count`, so operators can expose realistic NUMA/socket layouts instead of one flat
+    /// socket.
+    pub fn with_socket_topology(
+        mut self,
+        sockets: u8,
+        cores_per_socket: u8,
+    ) -> Result<Self, Error> {
+        if sockets == 0 || cores_per_socket == 0 {
+            return Err(Error::InvalidVmSpec(
+                "sockets and cores_per_socket cannot be 0.".to_string(),
+            ));
+        }
+
+        let threads_per_core = u32::from(self.cpus_per_core());
+        let total = u32::from(sockets) * u32::from(cores_per_socket) * threads_per_core;
+        if total != u32::from(self.cpu_count) {
+            return Err(Error::InvalidVmSpec(format!(
+                "sockets ({sockets}) * cores_per_socket ({cores_per_socket}) * threads_per_core \
+                 ({threads_per_core}) must equal cpu_count ({}).",
+                self.cpu_count
+            )));
+        }
+
+        // Keep cores_per_module in sync with the new per-socket core count, unless the caller
+        // separately overrode it (in which case it no longer matches the old cores_per_socket
+        // default from `new()`). Otherwise leaf 0x1F would disagree with leaves 0x4/0xB about
+        // how many cores share a package.
+        if self.cores_per_module == self.cores_per_socket {
+            self.cores_per_module = cores_per_socket;
+        }
+
+        self.sockets = sockets;
+        self.cores_per_socket = cores_per_socket;
+        Ok(self)
+    }
+
+    /// Overrides the architectural PMU passthrough mode (default: disabled).
+    pub fn with_pmu(mut self, pmu: PmuMode) -> Self {
+        self.pmu = pmu;
+        self
+    }
+
+    /// Sets the CPU template rules applied to leaf 0x7 (default: none).
+    pub fn with_feature_masks(mut self, feature_masks: Vec<CpuidFeatureMask>) -> Self {
+        self.feature_masks = feature_masks;
+        self
+    }
+
+    /// Overrides the number of cores per module (default: every core in the package).
+    pub fn with_cores_per_module(mut self, cores_per_module: u8) -> Result<Self, Error> {
+        if cores_per_module == 0 {
+            return Err(Error::InvalidVmSpec(
+                "cores_per_module cannot be 0.".to_string(),
+            ));
+        }
+        self.cores_per_module = cores_per_module;
+        Ok(self)
+    }
+
+    /// Overrides the number of modules per die (default: 1).
+    pub fn with_modules_per_die(mut self, modules_per_die: u8) -> Result<Self, Error> {
+        if modules_per_die == 0 {
+            return Err(Error::InvalidVmSpec("modules_per_die cannot be 0.".to_string()));
+        }
+        self.modules_per_die = modules_per_die;
+        Ok(self)
+    }
+
+    /// Overrides the number of dies per package (default: 1).
+    pub fn with_dies_per_package(mut self, dies_per_package: u8) -> Result<Self, Error> {
+        if dies_per_package == 0 {
+            return Err(Error::InvalidVmSpec(
+                "dies_per_package cannot be 0.".to_string(),
+            ));
+        }
+        self.dies_per_package = dies_per_package;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_socket_topology() {
+        let vm_spec = VmSpec::new(0, 8, true).expect("Error creating vm_spec");
+
+        // 2 sockets * 4 cores/socket * 2 threads/core == 16, not 8: rejected.
+        assert!(vm_spec.clone().with_socket_topology(2, 4).is_err());
+
+        // 2 sockets * 2 cores/socket * 2 threads/core == 8: matches cpu_count.
+        let vm_spec = vm_spec
+            .with_socket_topology(2, 2)
+            .expect("Error setting socket topology");
+        assert_eq!(vm_spec.sockets, 2);
+        assert_eq!(vm_spec.cores_per_socket, 2);
+    }
+
+    #[test]
+    fn test_with_socket_topology_keeps_cores_per_module_in_sync() {
+        // Default layout: 1 socket holding all 8 cores, so cores_per_module == cores_per_socket.
+        let vm_spec = VmSpec::new(0, 8, false).expect("Error creating vm_spec");
+        assert_eq!(vm_spec.cores_per_module, 8);
+
+        // Splitting into 2 sockets * 4 cores/socket must also update cores_per_module, so leaf
+        // 0x1F doesn't disagree with leaves 0x4/0xB about how many cores share a package.
+        let vm_spec = vm_spec
+            .with_socket_topology(2, 4)
+            .expect("Error setting socket topology");
+        assert_eq!(vm_spec.cores_per_module, 4);
+
+        // An explicit cores_per_module override that differs from cores_per_socket is kept
+        // across a later socket change.
+        let vm_spec = VmSpec::new(0, 8, false)
+            .expect("Error creating vm_spec")
+            .with_cores_per_module(1)
+            .expect("Error setting cores_per_module")
+            .with_socket_topology(2, 4)
+            .expect("Error setting socket topology");
+        assert_eq!(vm_spec.cores_per_module, 1);
+    }
+}